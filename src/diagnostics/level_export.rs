@@ -0,0 +1,272 @@
+use bevy::{asset::UntypedAssetId, prelude::*};
+use bevy_ecs_ldtk::prelude::*;
+use bevy_ecs_tilemap::map::TilemapTexture;
+use std::fs;
+
+/// Stands in for a texture handle in an exported scene, since handles
+/// themselves cannot be serialized.
+#[derive(Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct TexturePath(pub String);
+
+/// Stands in for a texture atlas in an exported scene. Layouts that were built
+/// in code rather than loaded from a file have no path.
+#[derive(Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct TextureAtlasPath {
+    pub layout: Option<String>,
+    pub index: usize,
+}
+
+/// Stands in for the textures of a tile layer in an exported scene.
+#[derive(Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct TilemapTexturePaths(pub Vec<String>);
+
+/// Returns the root entity along with every entity below it in the hierarchy.
+fn get_all_descendants(root: Entity, world: &World) -> Vec<Entity> {
+    let mut to_be_visited_entities = vec![root];
+    let mut seen_entities = Vec::new();
+
+    while let Some(current_entity) = to_be_visited_entities.pop() {
+        seen_entities.push(current_entity);
+
+        if let Some(children) = world.get::<Children>(current_entity) {
+            to_be_visited_entities.extend(children.iter());
+        }
+    }
+
+    seen_entities
+}
+
+fn get_asset_path(asset_server: &AssetServer, asset: impl Into<UntypedAssetId>) -> Option<String> {
+    asset_server.get_path(asset).map(|path| path.to_string())
+}
+
+/// Builds a scene out of the LDtk world rooted at level_root, with every
+/// component that holds an asset handle replaced by the asset paths it refers to.
+pub fn build_level_scene(level_root: Entity, world: &World) -> DynamicScene {
+    let level_entities = get_all_descendants(level_root, world);
+
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .deny::<Handle<Image>>()
+        .deny::<Handle<LdtkProject>>()
+        .deny::<TextureAtlas>()
+        .deny::<TilemapTexture>()
+        .extract_entities(level_entities.into_iter())
+        .build();
+
+    let asset_server = world.resource::<AssetServer>();
+    for scene_entity in scene.entities.iter_mut() {
+        let entity = scene_entity.entity;
+
+        let texture_path = world
+            .get::<Handle<Image>>(entity)
+            .and_then(|texture| get_asset_path(asset_server, texture.id()));
+
+        if let Some(path) = texture_path {
+            scene_entity.components.push(Box::new(TexturePath(path)));
+        }
+
+        if let Some(texture_atlas) = world.get::<TextureAtlas>(entity) {
+            scene_entity.components.push(Box::new(TextureAtlasPath {
+                layout: get_asset_path(asset_server, texture_atlas.layout.id()),
+                index: texture_atlas.index,
+            }));
+        }
+
+        if let Some(tilemap_texture) = world.get::<TilemapTexture>(entity) {
+            let tilemap_texture_paths = tilemap_texture
+                .image_handles()
+                .into_iter()
+                .filter_map(|texture| get_asset_path(asset_server, texture.id()))
+                .collect();
+
+            scene_entity
+                .components
+                .push(Box::new(TilemapTexturePaths(tilemap_texture_paths)));
+        }
+    }
+
+    scene
+}
+
+/// Writes the currently spawned level out to `<level name>.scn.ron` in the
+/// working directory whenever F12 is pressed.
+pub fn export_level_scene(world: &mut World) {
+    if !world
+        .resource::<ButtonInput<KeyCode>>()
+        .just_pressed(KeyCode::F12)
+    {
+        return;
+    }
+
+    let level_root = match world
+        .query_filtered::<Entity, With<Handle<LdtkProject>>>()
+        .get_single(world)
+    {
+        Ok(root) => root,
+        Err(_) => return,
+    };
+
+    let level_identifier = match world.resource::<LevelSelection>() {
+        LevelSelection::Identifier(name) => name.clone(),
+        _ => {
+            error!("export_level_scene: Expected Level Identifier.");
+            return;
+        }
+    };
+
+    let scene = build_level_scene(level_root, world);
+    let serialized_scene = match scene.serialize_ron(world.resource::<AppTypeRegistry>()) {
+        Ok(serialized_scene) => serialized_scene,
+        Err(error) => {
+            error!(
+                "export_level_scene: Level could not be serialized: {}",
+                error
+            );
+            return;
+        }
+    };
+
+    let export_path = format!("{}.scn.ron", level_identifier);
+    match fs::write(&export_path, serialized_scene) {
+        Ok(_) => info!("export_level_scene: Level exported to {}", export_path),
+        Err(error) => error!(
+            "export_level_scene: Could not write {}: {}",
+            export_path, error
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::player::Player;
+    use bevy::asset::AssetPlugin;
+
+    const TEST_TEXTURE_PATH: &str = "textures/characters/EeveeSprites.png";
+    const TEST_TILESET_PATH: &str = "textures/environments/Room_Builder_free_64x64.png";
+    const TEST_ATLAS_INDEX: usize = 1;
+
+    fn setup_app_level_export() -> App {
+        let mut app = App::new();
+
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<TextureAtlasLayout>()
+            .register_type::<Player>()
+            .register_type::<Handle<Image>>()
+            .register_type::<TextureAtlas>()
+            .register_type::<TilemapTexture>()
+            .register_type::<TexturePath>()
+            .register_type::<TextureAtlasPath>()
+            .register_type::<TilemapTexturePaths>();
+
+        app
+    }
+
+    #[test]
+    fn exports_level_descendants_only() {
+        let mut app = setup_app_level_export();
+
+        let level_root = app.world.spawn(Transform::default()).id();
+        let player = app.world.spawn((Player, Transform::default())).id();
+        app.world.entity_mut(level_root).add_child(player);
+        let camera = app.world.spawn(Transform::default()).id();
+
+        let scene = build_level_scene(level_root, &app.world);
+
+        let exported_entities = scene
+            .entities
+            .iter()
+            .map(|scene_entity| scene_entity.entity)
+            .collect::<Vec<Entity>>();
+
+        assert!(exported_entities.contains(&level_root));
+        assert!(exported_entities.contains(&player));
+        assert!(!exported_entities.contains(&camera));
+    }
+
+    #[test]
+    fn replaces_texture_handles_with_paths() {
+        let mut app = setup_app_level_export();
+
+        let texture: Handle<Image> = app.world.resource::<AssetServer>().load(TEST_TEXTURE_PATH);
+        let level_root = app.world.spawn(texture).id();
+
+        let scene = build_level_scene(level_root, &app.world);
+
+        let texture_path = find_exported_component::<TexturePath>(&scene, level_root)
+            .expect("replaces_texture_handles_with_paths [test]: Texture path was not exported");
+
+        assert_eq!(TEST_TEXTURE_PATH, texture_path.0);
+        assert!(scene
+            .serialize_ron(app.world.resource::<AppTypeRegistry>())
+            .is_ok());
+    }
+
+    fn find_exported_component<T: Reflect>(scene: &DynamicScene, entity: Entity) -> Option<&T> {
+        scene
+            .entities
+            .iter()
+            .find(|scene_entity| scene_entity.entity == entity)?
+            .components
+            .iter()
+            .find_map(|component| component.downcast_ref::<T>())
+    }
+
+    #[test]
+    fn replaces_sprite_sheet_and_tilemap_handles_with_paths() {
+        let mut app = setup_app_level_export();
+
+        let texture: Handle<Image> = app.world.resource::<AssetServer>().load(TEST_TEXTURE_PATH);
+        let tileset: Handle<Image> = app.world.resource::<AssetServer>().load(TEST_TILESET_PATH);
+        let layout = app.world.resource_mut::<Assets<TextureAtlasLayout>>().add(
+            TextureAtlasLayout::from_grid(Vec2::new(64.0, 64.0), 1, 4, None, None),
+        );
+
+        let level_root = app.world.spawn(Transform::default()).id();
+        let player = app
+            .world
+            .spawn((
+                Player,
+                SpriteSheetBundle {
+                    texture,
+                    atlas: TextureAtlas {
+                        layout,
+                        index: TEST_ATLAS_INDEX,
+                    },
+                    ..default()
+                },
+            ))
+            .id();
+        let tile_layer = app.world.spawn(TilemapTexture::Single(tileset)).id();
+        app.world
+            .entity_mut(level_root)
+            .push_children(&[player, tile_layer]);
+
+        let scene = build_level_scene(level_root, &app.world);
+
+        assert!(scene
+            .serialize_ron(app.world.resource::<AppTypeRegistry>())
+            .is_ok());
+
+        let texture_path = find_exported_component::<TexturePath>(&scene, player).expect(
+            "replaces_sprite_sheet_and_tilemap_handles_with_paths [test]: Sprite sheet texture path was not exported",
+        );
+        assert_eq!(TEST_TEXTURE_PATH, texture_path.0);
+
+        let texture_atlas_path = find_exported_component::<TextureAtlasPath>(&scene, player)
+            .expect(
+                "replaces_sprite_sheet_and_tilemap_handles_with_paths [test]: Texture atlas was not exported",
+            );
+        assert_eq!(TEST_ATLAS_INDEX, texture_atlas_path.index);
+
+        let tilemap_texture_paths =
+            find_exported_component::<TilemapTexturePaths>(&scene, tile_layer).expect(
+                "replaces_sprite_sheet_and_tilemap_handles_with_paths [test]: Tilemap texture paths were not exported",
+            );
+        assert_eq!(vec![TEST_TILESET_PATH.to_string()], tilemap_texture_paths.0);
+    }
+}
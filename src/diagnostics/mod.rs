@@ -1 +1,3 @@
+#[cfg(debug_assertions)]
+pub mod level_export;
 pub mod missing_file_finder;
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 
-#[derive(Default, Component)]
+#[derive(Default, Component, Reflect)]
+#[reflect(Component)]
 pub struct Player;
 
 #[derive(Default, Component, Resource)]
@@ -16,7 +17,8 @@ pub enum PlayerMovementActions {
     Bumping,
 }
 
-#[derive(Default, Component)]
+#[derive(Default, Component, Reflect)]
+#[reflect(Component)]
 pub enum DirectionFacing {
     #[default]
     Up,
@@ -25,7 +27,20 @@ pub enum DirectionFacing {
     Right,
 }
 
-#[derive(Default, Component, PartialEq)]
+impl DirectionFacing {
+    /// Returns the unit vector pointing in the direction being faced.
+    pub fn to_vec3(&self) -> Vec3 {
+        match self {
+            DirectionFacing::Up => Vec3::Y,
+            DirectionFacing::Down => Vec3::NEG_Y,
+            DirectionFacing::Left => Vec3::NEG_X,
+            DirectionFacing::Right => Vec3::X,
+        }
+    }
+}
+
+#[derive(Default, Component, PartialEq, Reflect)]
+#[reflect(Component)]
 pub enum MovementIntent {
     #[default]
     Idle,
@@ -37,6 +52,9 @@ pub struct PlayerBundle {
     #[sprite_sheet_bundle("textures/characters/EeveeSprites.png", 64.0, 64.0, 1, 4, 0.0, 0.0, 0)]
     sprite: SpriteSheetBundle,
 
+    #[from_entity_instance]
+    entity_instance: EntityInstance,
+
     player: Player,
     direction_facing: DirectionFacing,
     movement_intent: MovementIntent,
@@ -8,10 +8,44 @@ use bevy::math::bounding::{Aabb2d, IntersectsVolume};
 use bevy::prelude::*;
 use bevy_ecs_ldtk::LevelSelection;
 use bevy_ecs_ldtk::{prelude::*, EntityInstance, LevelIid};
+use std::collections::VecDeque;
 
 #[derive(Event)]
 pub struct InteractionEvent(String, String);
 
+#[derive(Event)]
+pub struct ReturnToPreviousMap;
+
+/// How many level changes are remembered for returning to previous maps.
+const LEVEL_HISTORY_DEPTH: usize = 8;
+
+/// A level the player has left, along with where they were standing when they left it.
+#[derive(Reflect)]
+pub struct VisitedLevel {
+    pub identifier: String,
+    pub player_position: Vec3,
+}
+
+/// The most recent levels the player has transitioned out of, most recent last.
+#[derive(Default, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct LevelHistory {
+    pub visited_levels: VecDeque<VisitedLevel>,
+    pub return_position: Option<Vec3>,
+}
+
+impl LevelHistory {
+    /// Remembers a level the player has left, forgetting the oldest one once the
+    /// history is full.
+    pub fn push(&mut self, visited_level: VisitedLevel) {
+        self.visited_levels.push_back(visited_level);
+
+        if self.visited_levels.len() > LEVEL_HISTORY_DEPTH {
+            self.visited_levels.pop_front();
+        }
+    }
+}
+
 pub fn player_input(
     input: Res<ButtonInput<KeyCode>>,
     mut player_query: Query<(&mut DirectionFacing, &mut MovementIntent), With<Player>>,
@@ -69,6 +103,7 @@ pub fn update_level_dimensions(
 pub fn bound_player_movement(
     level_dimension: Res<LevelDimensions>,
     mut player_query: Query<&mut Transform, (Changed<Transform>, With<Player>)>,
+    player_instance_query: Query<&EntityInstance, With<Player>>,
 ) {
     if player_query.is_empty() {
         return;
@@ -82,17 +117,21 @@ pub fn bound_player_movement(
         .get_single_mut()
         .expect("bound_player_movement: The player does not exist, but they should");
 
-    let tile_side_length = 64.0;
-    let tile_mid_point = tile_side_length / 2.0;
+    let player_instance = player_instance_query
+        .get_single()
+        .expect("bound_player_movement: The player has no LDtk Entity Instance, but they should");
+
+    let player_mid_point_x = player_instance.width as f32 / 2.0;
+    let player_mid_point_y = player_instance.height as f32 / 2.0;
 
     player_transform.translation.x = player_transform.translation.x.clamp(
-        tile_mid_point,
-        level_dimension.width as f32 - tile_mid_point,
+        player_mid_point_x,
+        level_dimension.width as f32 - player_mid_point_x,
     );
 
     player_transform.translation.y = player_transform.translation.y.clamp(
-        tile_mid_point,
-        level_dimension.height as f32 - tile_mid_point,
+        player_mid_point_y,
+        level_dimension.height as f32 - player_mid_point_y,
     );
 }
 
@@ -123,11 +162,17 @@ pub fn animate_entity(
 
 pub fn move_entity(
     mut entity_query: Query<
-        (&mut Transform, &DirectionFacing, &mut MovementIntent),
+        (
+            &mut Transform,
+            &EntityInstance,
+            &DirectionFacing,
+            &mut MovementIntent,
+        ),
         Changed<MovementIntent>,
     >,
     tile_query: Query<&EntityInstance>,
     level_dimension: Res<LevelDimensions>,
+    time: Res<Time>,
     mut entity_movement_broadcast: EventWriter<PlayerMovementActions>,
 ) {
     if entity_query.is_empty() {
@@ -144,31 +189,23 @@ pub fn move_entity(
         })
         .collect::<Vec<&EntityInstance>>();
 
-    for (mut entity_transform, facing, mut moving) in entity_query.iter_mut() {
-        let pixel_distance = 3.0;
-        let mut direction = Vec3::ZERO;
+    for (mut entity_transform, entity_instance, facing, mut moving) in entity_query.iter_mut() {
+        // Scaled by the frame time so that movement speed does not depend on frame rate.
+        let pixels_per_second = 180.0;
+        let pixel_distance = pixels_per_second * time.delta_seconds();
 
         if *moving != MovementIntent::Moving {
             return;
         }
 
-        match facing {
-            DirectionFacing::Up => {
-                direction += Vec3::new(0.0, pixel_distance, 0.0);
-            }
-            DirectionFacing::Down => {
-                direction -= Vec3::new(0.0, pixel_distance, 0.0);
-            }
-            DirectionFacing::Left => {
-                direction -= Vec3::new(pixel_distance, 0.0, 0.0);
-            }
-            DirectionFacing::Right => {
-                direction += Vec3::new(pixel_distance, 0.0, 0.0);
-            }
-        }
+        let direction = facing.to_vec3();
 
-        let tile_side_length = 64.0;
-        let projected_position = entity_transform.translation + direction;
+        let projected_position = entity_transform.translation + direction * pixel_distance;
+        let projected_dimensions =
+            Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+
+        let mut travel_distance = pixel_distance;
+        let mut has_bumped = false;
 
         for &collision_tile in collision_tiles.iter() {
             let tile_position = Vec3::new(
@@ -177,7 +214,6 @@ pub fn move_entity(
                 0.0,
             );
 
-            let projected_dimensions = Vec2::new(tile_side_length, tile_side_length);
             let tile_dimensions =
                 Vec2::new(collision_tile.width as f32, collision_tile.height as f32);
 
@@ -186,15 +222,42 @@ pub fn move_entity(
                     &Aabb2d::new(tile_position.truncate(), tile_dimensions / 2.0),
                 );
 
-            if has_collided {
-                entity_movement_broadcast.send(PlayerMovementActions::Bumping);
-                *moving = MovementIntent::Idle;
-                return;
+            if !has_collided {
+                continue;
             }
+
+            let tile_offset = (tile_position - entity_transform.translation).truncate();
+            let combined_half_dimensions = (projected_dimensions + tile_dimensions) / 2.0;
+            let movement_axis = direction.truncate();
+            let cross_axis = movement_axis.perp().abs();
+
+            // Tiles that are behind the entity, or only touching its sides, must not block it,
+            // otherwise it could not slide along a wall it is flush against.
+            let is_ahead = tile_offset.dot(movement_axis) > 0.0;
+            let overlaps_across =
+                tile_offset.dot(cross_axis).abs() < combined_half_dimensions.dot(cross_axis);
+
+            if !is_ahead || !overlaps_across {
+                continue;
+            }
+
+            // Stop flush against the tile rather than dropping the whole step, so that
+            // where the entity comes to rest does not depend on frame rate.
+            let distance_to_tile =
+                tile_offset.dot(movement_axis) - combined_half_dimensions.dot(movement_axis.abs());
+
+            travel_distance = travel_distance.min(distance_to_tile.max(0.0));
+            has_bumped = true;
+        }
+
+        entity_transform.translation += direction * travel_distance;
+
+        if has_bumped {
+            entity_movement_broadcast.send(PlayerMovementActions::Bumping);
+        } else {
+            entity_movement_broadcast.send(PlayerMovementActions::Walking);
         }
 
-        entity_transform.translation = projected_position;
-        entity_movement_broadcast.send(PlayerMovementActions::Walking);
         *moving = MovementIntent::Idle;
     }
 }
@@ -202,7 +265,7 @@ pub fn move_entity(
 pub fn interact_entity(
     input: Res<ButtonInput<KeyCode>>,
     tile_query: Query<&EntityInstance>,
-    player_query: Query<(&Transform, &DirectionFacing), With<Player>>,
+    player_query: Query<(&Transform, &EntityInstance, &DirectionFacing), With<Player>>,
     level_dimension: Res<LevelDimensions>,
     mut interactible_event_writer: EventWriter<InteractionEvent>,
 ) {
@@ -224,30 +287,16 @@ pub fn interact_entity(
         })
         .collect::<Vec<&EntityInstance>>();
 
-    let (player_transform, facing) = player_query
+    let (player_transform, player_instance, facing) = player_query
         .get_single()
         .expect("interact_entity: The player does not exist, but they should");
 
     let pixel_distance = 3.0;
-    let mut direction = Vec3::ZERO;
-
-    match facing {
-        DirectionFacing::Up => {
-            direction += Vec3::new(0.0, pixel_distance, 0.0);
-        }
-        DirectionFacing::Down => {
-            direction -= Vec3::new(0.0, pixel_distance, 0.0);
-        }
-        DirectionFacing::Left => {
-            direction -= Vec3::new(pixel_distance, 0.0, 0.0);
-        }
-        DirectionFacing::Right => {
-            direction += Vec3::new(pixel_distance, 0.0, 0.0);
-        }
-    }
+    let direction = facing.to_vec3() * pixel_distance;
 
-    let tile_side_length = 64.0;
     let projected_position = player_transform.translation + direction;
+    let projected_dimensions =
+        Vec2::new(player_instance.width as f32, player_instance.height as f32);
 
     for &interactive_tile in interactive_tiles.iter() {
         let tile_position = Vec3::new(
@@ -256,7 +305,6 @@ pub fn interact_entity(
             0.0,
         );
 
-        let projected_dimensions = Vec2::new(tile_side_length, tile_side_length);
         let tile_dimensions = Vec2::new(
             interactive_tile.width as f32,
             interactive_tile.height as f32,
@@ -302,7 +350,9 @@ pub fn display_interactive_message(mut interactible_event_reader: EventReader<In
 
 pub fn transition_level(
     mut interactible_event_reader: EventReader<InteractionEvent>,
+    player_query: Query<&Transform, With<Player>>,
     mut level: ResMut<LevelSelection>,
+    mut level_history: ResMut<LevelHistory>,
 ) {
     for interaction_command in interactible_event_reader.read() {
         let command = &interaction_command.0;
@@ -310,14 +360,91 @@ pub fn transition_level(
             continue;
         }
 
+        let player_transform = player_query
+            .get_single()
+            .expect("transition_level: The player does not exist, but they should");
+
+        let current_level = match &*level {
+            LevelSelection::Identifier(name) => name.clone(),
+            _ => panic!("transition_level: Expected Level Identifier."),
+        };
+
+        level_history.push(VisitedLevel {
+            identifier: current_level,
+            player_position: player_transform.translation,
+        });
+
         let arg = &interaction_command.1;
         *level = LevelSelection::Identifier(arg.to_string());
     }
 }
 
+pub fn request_return_to_previous_map(
+    mut interactible_event_reader: EventReader<InteractionEvent>,
+    mut return_broadcast: EventWriter<ReturnToPreviousMap>,
+) {
+    for interaction_command in interactible_event_reader.read() {
+        let command = &interaction_command.0;
+        if command != "return" {
+            continue;
+        }
+
+        return_broadcast.send(ReturnToPreviousMap);
+    }
+}
+
+pub fn return_to_previous_map(
+    mut return_event_reader: EventReader<ReturnToPreviousMap>,
+    mut level: ResMut<LevelSelection>,
+    mut level_history: ResMut<LevelHistory>,
+) {
+    for _ in return_event_reader.read() {
+        let previous_level = match level_history.visited_levels.pop_back() {
+            Some(visited_level) => visited_level,
+            None => continue,
+        };
+
+        let previous_selection = LevelSelection::Identifier(previous_level.identifier);
+
+        // The level is only respawned if the selection changes, so a return position
+        // for the current level would be left over for the next unrelated spawn.
+        if *level == previous_selection {
+            continue;
+        }
+
+        *level = previous_selection;
+        level_history.return_position = Some(previous_level.player_position);
+    }
+}
+
+/// Moves a freshly spawned player to where they stood before leaving the level,
+/// if they got there by returning to it.
+pub fn place_returning_player(
+    mut player_query: Query<&mut Transform, Added<Player>>,
+    mut level_history: ResMut<LevelHistory>,
+) {
+    if player_query.is_empty() {
+        return;
+    }
+
+    let return_position = match level_history.return_position.take() {
+        Some(position) => position,
+        None => return,
+    };
+
+    let mut player_transform = player_query
+        .get_single_mut()
+        .expect("place_returning_player: Expected a single player to be spawned");
+
+    player_transform.translation.x = return_position.x;
+    player_transform.translation.y = return_position.y;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
 
     const TEST_LEVEL_WIDTH: usize = 1344;
     const TEST_LEVEL_HEIGHT: usize = 1472;
@@ -330,8 +457,26 @@ mod tests {
     const TEST_LEVEL_HEIGHT_OUT_TBOUNDS: f32 = 1600.0;
     const TEST_LEVEL_HEIGHT_OUT_BBOUNDS: f32 = -500.0;
 
+    const TEST_PLAYER_SIDE_LENGTH: i32 = 64;
     const PLAYER_MIDPOINT: usize = 32;
 
+    const TEST_SMALL_PLAYER_WIDTH: i32 = 32;
+    const TEST_SMALL_PLAYER_HEIGHT: i32 = 48;
+
+    const TEST_PLAYER_SPEED: f32 = 180.0;
+    const TEST_MOVEMENT_SECONDS: u32 = 1;
+
+    const TEST_WALL_SIDE_LENGTH: i32 = 64;
+    const TEST_DISTANCE_TO_WALL: f32 = 100.0;
+
+    fn test_player_instance() -> EntityInstance {
+        EntityInstance {
+            width: TEST_PLAYER_SIDE_LENGTH,
+            height: TEST_PLAYER_SIDE_LENGTH,
+            ..default()
+        }
+    }
+
     fn setup_app_bounds_checking() -> App {
         let mut app = App::new();
 
@@ -354,6 +499,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0),
             ))
             .id();
@@ -380,6 +526,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_LBOUNDS,
                     TEST_LEVEL_HEIGHT_IN_BOUNDS,
@@ -410,6 +557,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_LBOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_TBOUNDS,
@@ -443,6 +591,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_LBOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_BBOUNDS,
@@ -473,6 +622,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_RBOUNDS,
                     TEST_LEVEL_HEIGHT_IN_BOUNDS,
@@ -506,6 +656,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_RBOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_TBOUNDS,
@@ -539,6 +690,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_OUT_RBOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_BBOUNDS,
@@ -572,6 +724,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_IN_BOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_TBOUNDS,
@@ -605,6 +758,7 @@ mod tests {
             .spawn_empty()
             .insert((
                 Player,
+                test_player_instance(),
                 Transform::from_xyz(
                     TEST_LEVEL_WIDTH_IN_BOUNDS,
                     TEST_LEVEL_HEIGHT_OUT_BBOUNDS,
@@ -625,4 +779,349 @@ mod tests {
 
         assert_eq!(expected_transform, actual_transform);
     }
+
+    #[test]
+    fn out_of_bounds_topright_uses_player_size() {
+        let mut app = setup_app_bounds_checking();
+
+        let player_id = app
+            .world
+            .spawn_empty()
+            .insert((
+                Player,
+                EntityInstance {
+                    width: TEST_SMALL_PLAYER_WIDTH,
+                    height: TEST_SMALL_PLAYER_HEIGHT,
+                    ..default()
+                },
+                Transform::from_xyz(
+                    TEST_LEVEL_WIDTH_OUT_RBOUNDS,
+                    TEST_LEVEL_HEIGHT_OUT_TBOUNDS,
+                    0.0,
+                ),
+            ))
+            .id();
+
+        app.update();
+
+        let player_query = app.world.get::<Transform>(player_id);
+        assert!(player_query.is_some());
+
+        let expected_transform = Transform::from_xyz(
+            (TEST_LEVEL_WIDTH as i32 - TEST_SMALL_PLAYER_WIDTH / 2) as f32,
+            (TEST_LEVEL_HEIGHT as i32 - TEST_SMALL_PLAYER_HEIGHT / 2) as f32,
+            0.0,
+        );
+        let actual_transform = *player_query
+            .expect("out_of_bounds_topright_uses_player_size [test]: Player could not be found");
+
+        assert_eq!(expected_transform, actual_transform);
+    }
+
+    fn setup_app_movement(frames_per_second: u32) -> App {
+        let mut app = App::new();
+
+        let frame_duration = Duration::from_secs_f64(1.0 / frames_per_second as f64);
+
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame_duration))
+            .insert_resource(LevelDimensions {
+                width: TEST_LEVEL_WIDTH,
+                height: TEST_LEVEL_HEIGHT,
+            })
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<PlayerMovementActions>();
+
+        app.world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+
+        app.add_systems(Update, (player_input, move_entity).chain());
+
+        app
+    }
+
+    /// A wall that the player, walking right, reaches after TEST_DISTANCE_TO_WALL pixels.
+    fn test_wall_instance() -> EntityInstance {
+        let wall_x = TEST_LEVEL_WIDTH_IN_BOUNDS
+            + PLAYER_MIDPOINT as f32
+            + TEST_DISTANCE_TO_WALL
+            + (TEST_WALL_SIDE_LENGTH / 2) as f32;
+
+        EntityInstance {
+            px: IVec2::new(
+                wall_x as i32,
+                TEST_LEVEL_HEIGHT as i32 - TEST_LEVEL_HEIGHT_IN_BOUNDS as i32,
+            ),
+            width: TEST_WALL_SIDE_LENGTH,
+            height: TEST_WALL_SIDE_LENGTH,
+            field_instances: vec![ldtk::FieldInstance {
+                identifier: "Traversable".to_string(),
+                tile: None,
+                field_instance_type: "Bool".to_string(),
+                value: FieldValue::Bool(false),
+                def_uid: 0,
+                real_editor_values: Vec::new(),
+            }],
+            ..default()
+        }
+    }
+
+    fn distance_walked_at(frames_per_second: u32, wall: Option<EntityInstance>) -> f32 {
+        let mut app = setup_app_movement(frames_per_second);
+
+        if let Some(wall_instance) = wall {
+            app.world.spawn(wall_instance);
+        }
+
+        // Time reports no elapsed time on the very first update, so that frame is
+        // spent before the player exists.
+        app.update();
+
+        let player_id = app
+            .world
+            .spawn_empty()
+            .insert((
+                Player,
+                test_player_instance(),
+                Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0),
+                DirectionFacing::Right,
+                MovementIntent::Idle,
+            ))
+            .id();
+
+        for _ in 0..(frames_per_second * TEST_MOVEMENT_SECONDS) {
+            app.update();
+        }
+
+        let player_transform = app
+            .world
+            .get::<Transform>(player_id)
+            .expect("distance_walked_at [test]: Player could not be found");
+
+        player_transform.translation.x - TEST_LEVEL_WIDTH_IN_BOUNDS
+    }
+
+    #[test]
+    fn movement_at_30_fps() {
+        let expected_distance = TEST_PLAYER_SPEED * TEST_MOVEMENT_SECONDS as f32;
+        let actual_distance = distance_walked_at(30, None);
+
+        assert!((expected_distance - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn movement_at_60_fps() {
+        let expected_distance = TEST_PLAYER_SPEED * TEST_MOVEMENT_SECONDS as f32;
+        let actual_distance = distance_walked_at(60, None);
+
+        assert!((expected_distance - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn movement_at_144_fps() {
+        let expected_distance = TEST_PLAYER_SPEED * TEST_MOVEMENT_SECONDS as f32;
+        let actual_distance = distance_walked_at(144, None);
+
+        assert!((expected_distance - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn wall_approach_at_30_fps() {
+        let actual_distance = distance_walked_at(30, Some(test_wall_instance()));
+
+        assert!((TEST_DISTANCE_TO_WALL - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn wall_approach_at_60_fps() {
+        let actual_distance = distance_walked_at(60, Some(test_wall_instance()));
+
+        assert!((TEST_DISTANCE_TO_WALL - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn wall_approach_at_144_fps() {
+        let actual_distance = distance_walked_at(144, Some(test_wall_instance()));
+
+        assert!((TEST_DISTANCE_TO_WALL - actual_distance).abs() < 0.01);
+    }
+
+    #[test]
+    fn slide_along_wall_after_reaching_it() {
+        let frames_per_second = 60;
+        let mut app = setup_app_movement(frames_per_second);
+        app.world.spawn(test_wall_instance());
+
+        app.update();
+
+        let player_id = app
+            .world
+            .spawn((
+                Player,
+                test_player_instance(),
+                Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0),
+                DirectionFacing::Right,
+                MovementIntent::Idle,
+            ))
+            .id();
+
+        for _ in 0..(frames_per_second * TEST_MOVEMENT_SECONDS) {
+            app.update();
+        }
+
+        let mut input = app.world.resource_mut::<ButtonInput<KeyCode>>();
+        input.release(KeyCode::KeyD);
+        input.press(KeyCode::KeyW);
+
+        for _ in 0..(frames_per_second * TEST_MOVEMENT_SECONDS) {
+            app.update();
+        }
+
+        let expected_transform = Transform::from_xyz(
+            TEST_LEVEL_WIDTH_IN_BOUNDS + TEST_DISTANCE_TO_WALL,
+            TEST_LEVEL_HEIGHT_IN_BOUNDS + TEST_PLAYER_SPEED * TEST_MOVEMENT_SECONDS as f32,
+            0.0,
+        );
+        let actual_transform = *app
+            .world
+            .get::<Transform>(player_id)
+            .expect("slide_along_wall_after_reaching_it [test]: Player could not be found");
+
+        assert!((expected_transform.translation - actual_transform.translation).length() < 0.01);
+    }
+
+    fn setup_app_level_history() -> App {
+        let mut app = App::new();
+
+        app.insert_resource(LevelSelection::Identifier("Level_0".to_string()))
+            .init_resource::<LevelHistory>()
+            .add_event::<InteractionEvent>()
+            .add_event::<ReturnToPreviousMap>();
+
+        app.add_systems(
+            Update,
+            (
+                transition_level,
+                request_return_to_previous_map,
+                return_to_previous_map.after(request_return_to_previous_map),
+                place_returning_player,
+            ),
+        );
+
+        app
+    }
+
+    #[test]
+    fn return_to_previous_map_after_transition() {
+        let mut app = setup_app_level_history();
+
+        let player_id = app
+            .world
+            .spawn((
+                Player,
+                Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0),
+            ))
+            .id();
+
+        app.world.send_event(InteractionEvent(
+            "transition".to_string(),
+            "Level_1".to_string(),
+        ));
+        app.update();
+
+        assert_eq!(
+            LevelSelection::Identifier("Level_1".to_string()),
+            *app.world.resource::<LevelSelection>()
+        );
+
+        // The level change replaces the player with the one spawned in the new level.
+        app.world.despawn(player_id);
+        app.world.spawn((Player, Transform::default()));
+        app.update();
+
+        app.world
+            .send_event(InteractionEvent("return".to_string(), String::new()));
+        app.update();
+
+        assert_eq!(
+            LevelSelection::Identifier("Level_0".to_string()),
+            *app.world.resource::<LevelSelection>()
+        );
+
+        let returning_player_id = app.world.spawn((Player, Transform::default())).id();
+        app.update();
+
+        let expected_transform =
+            Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0);
+        let actual_transform = *app
+            .world
+            .get::<Transform>(returning_player_id)
+            .expect("return_to_previous_map_after_transition [test]: Player could not be found");
+
+        assert_eq!(expected_transform, actual_transform);
+    }
+
+    #[test]
+    fn level_history_forgets_oldest_levels() {
+        let mut app = setup_app_level_history();
+
+        app.world.spawn((Player, Transform::default()));
+
+        for level_number in 1..=(LEVEL_HISTORY_DEPTH + 1) {
+            app.world.send_event(InteractionEvent(
+                "transition".to_string(),
+                format!("Level_{}", level_number),
+            ));
+            app.update();
+        }
+
+        let level_history = app.world.resource::<LevelHistory>();
+        assert_eq!(LEVEL_HISTORY_DEPTH, level_history.visited_levels.len());
+
+        let oldest_level = level_history.visited_levels.front().expect(
+            "level_history_forgets_oldest_levels [test]: Level history should not be empty",
+        );
+        assert_eq!("Level_1", oldest_level.identifier);
+    }
+
+    #[test]
+    fn return_to_current_level_keeps_no_return_position() {
+        let mut app = setup_app_level_history();
+
+        app.world.spawn((
+            Player,
+            Transform::from_xyz(TEST_LEVEL_WIDTH_IN_BOUNDS, TEST_LEVEL_HEIGHT_IN_BOUNDS, 0.0),
+        ));
+
+        app.world.send_event(InteractionEvent(
+            "transition".to_string(),
+            "Level_0".to_string(),
+        ));
+        app.update();
+
+        app.world.send_event(ReturnToPreviousMap);
+        app.update();
+
+        assert_eq!(
+            LevelSelection::Identifier("Level_0".to_string()),
+            *app.world.resource::<LevelSelection>()
+        );
+
+        let level_history = app.world.resource::<LevelHistory>();
+        assert!(level_history.return_position.is_none());
+    }
+
+    #[test]
+    fn return_without_history_keeps_level() {
+        let mut app = setup_app_level_history();
+
+        app.world.send_event(ReturnToPreviousMap);
+        app.update();
+
+        assert_eq!(
+            LevelSelection::Identifier("Level_0".to_string()),
+            *app.world.resource::<LevelSelection>()
+        );
+    }
 }
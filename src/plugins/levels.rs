@@ -2,6 +2,8 @@ use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 use bevy_kira_audio::AudioApp;
 
+#[cfg(debug_assertions)]
+use crate::diagnostics::level_export::*;
 use crate::{
     audio::music::{play_level_music, MusicChannel},
     mechanics::{camera::*, input::*},
@@ -16,6 +18,8 @@ impl Plugin for LevelsPlugin {
         app.add_systems(OnEnter(AppState::InGame), spawn_map)
             .insert_resource(LevelSelection::Identifier("Level_0".to_string()))
             .init_resource::<LevelDimensions>()
+            .register_type::<LevelDimensions>()
+            .add_event::<SetLayerVisibility>()
             .add_systems(
                 Update,
                 (
@@ -24,9 +28,21 @@ impl Plugin for LevelsPlugin {
                     play_level_music,
                     update_level_dimensions,
                     update_camera_on_resolution_change,
+                    hide_invisible_layers,
+                    set_layer_visibility.after(hide_invisible_layers),
                 )
                     .run_if(in_state(AppState::InGame)),
             )
             .add_audio_channel::<MusicChannel>();
+
+        // Level exporting writes files on a keypress, so it is left out of release builds.
+        #[cfg(debug_assertions)]
+        app.register_type::<TexturePath>()
+            .register_type::<TextureAtlasPath>()
+            .register_type::<TilemapTexturePaths>()
+            .add_systems(
+                Update,
+                export_level_scene.run_if(in_state(AppState::InGame)),
+            );
     }
 }
@@ -20,6 +20,9 @@ impl Plugin for PlayableCharacterPlugin {
                 interact_entity,
                 display_interactive_message.after(interact_entity),
                 transition_level.after(interact_entity),
+                request_return_to_previous_map.after(interact_entity),
+                return_to_previous_map.after(request_return_to_previous_map),
+                place_returning_player,
                 bound_player_movement,
                 play_player_movement_sound.after(move_entity),
                 play_player_bump_sound.after(move_entity),
@@ -30,6 +33,13 @@ impl Plugin for PlayableCharacterPlugin {
         .add_audio_channel::<PlayerBumpChannel>()
         .add_event::<PlayerMovementActions>()
         .add_event::<InteractionEvent>()
+        .add_event::<ReturnToPreviousMap>()
+        .init_resource::<LevelHistory>()
+        .register_type::<LevelHistory>()
+        .register_type::<VisitedLevel>()
+        .register_type::<Player>()
+        .register_type::<DirectionFacing>()
+        .register_type::<MovementIntent>()
         .register_ldtk_entity::<PlayerBundle>("Player");
     }
 }
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 
-#[derive(Default, Resource)]
+#[derive(Default, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct LevelDimensions {
     pub width: usize,
     pub height: usize,
@@ -17,3 +18,123 @@ pub fn spawn_map(mut commands: Commands, asset_spawner: Res<AssetServer>) {
         ..default()
     });
 }
+
+/// Shows or hides every tile of the LDtk layer with the given identifier.
+#[derive(Event)]
+pub struct SetLayerVisibility {
+    pub layer: String,
+    pub visible: bool,
+}
+
+fn layer_visibility(visible: bool) -> Visibility {
+    if visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
+
+/// Hides newly spawned layers that were marked as invisible in LDtk.
+pub fn hide_invisible_layers(
+    mut layer_query: Query<(&LayerMetadata, &mut Visibility), Added<LayerMetadata>>,
+) {
+    for (layer_metadata, mut visibility) in layer_query.iter_mut() {
+        *visibility = layer_visibility(layer_metadata.visible);
+    }
+}
+
+pub fn set_layer_visibility(
+    mut layer_visibility_events: EventReader<SetLayerVisibility>,
+    mut layer_query: Query<(&LayerMetadata, &mut Visibility)>,
+) {
+    for layer_visibility_event in layer_visibility_events.read() {
+        for (layer_metadata, mut visibility) in layer_query.iter_mut() {
+            if layer_metadata.identifier != layer_visibility_event.layer {
+                continue;
+            }
+
+            *visibility = layer_visibility(layer_visibility_event.visible);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_LAYER_IDENTIFIER: &str = "Walls_Foreground";
+    const TEST_OTHER_LAYER_IDENTIFIER: &str = "Floor";
+
+    fn setup_app_layer_visibility() -> App {
+        let mut app = App::new();
+
+        app.add_event::<SetLayerVisibility>().add_systems(
+            Update,
+            (hide_invisible_layers, set_layer_visibility).chain(),
+        );
+
+        app
+    }
+
+    fn spawn_layer(app: &mut App, identifier: &str, visible: bool) -> Entity {
+        app.world
+            .spawn((
+                LayerMetadata {
+                    identifier: identifier.to_string(),
+                    visible,
+                    ..default()
+                },
+                Visibility::Inherited,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn invisible_layer_is_hidden_on_spawn() {
+        let mut app = setup_app_layer_visibility();
+
+        let hidden_layer = spawn_layer(&mut app, TEST_LAYER_IDENTIFIER, false);
+        let shown_layer = spawn_layer(&mut app, TEST_OTHER_LAYER_IDENTIFIER, true);
+
+        app.update();
+
+        assert_eq!(
+            Some(&Visibility::Hidden),
+            app.world.get::<Visibility>(hidden_layer)
+        );
+        assert_eq!(
+            Some(&Visibility::Inherited),
+            app.world.get::<Visibility>(shown_layer)
+        );
+    }
+
+    #[test]
+    fn set_layer_visibility_only_affects_named_layer() {
+        let mut app = setup_app_layer_visibility();
+
+        let toggled_layer = spawn_layer(&mut app, TEST_LAYER_IDENTIFIER, false);
+        let other_layer = spawn_layer(&mut app, TEST_OTHER_LAYER_IDENTIFIER, true);
+
+        app.update();
+
+        app.world.send_event(SetLayerVisibility {
+            layer: TEST_LAYER_IDENTIFIER.to_string(),
+            visible: true,
+        });
+        app.world.send_event(SetLayerVisibility {
+            layer: TEST_OTHER_LAYER_IDENTIFIER.to_string(),
+            visible: false,
+        });
+
+        app.update();
+
+        assert_eq!(
+            Some(&Visibility::Inherited),
+            app.world.get::<Visibility>(toggled_layer)
+        );
+        assert_eq!(
+            Some(&Visibility::Hidden),
+            app.world.get::<Visibility>(other_layer)
+        );
+    }
+}